@@ -0,0 +1,63 @@
+use crate::next_array::ArrayBuilder;
+
+/// An iterator that groups the elements of an underlying iterator into
+/// non-overlapping `[T; N]` chunks.
+///
+/// See [`array_chunks`] for more information.
+pub struct ArrayChunks<I, const N: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+    builder: ArrayBuilder<I::Item, N>,
+}
+
+/// Equivalent to `it.array_chunks::<N>()`.
+pub fn array_chunks<I, const N: usize>(iter: I) -> ArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    ArrayChunks {
+        iter,
+        builder: ArrayBuilder::new(),
+    }
+}
+
+impl<I, const N: usize> ArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    /// Returns an iterator over the elements of the last, incomplete chunk, if
+    /// any remain.
+    ///
+    /// Only meaningful after the adapter has returned `None`: if the
+    /// underlying iterator's length was a multiple of `N`, or this is called
+    /// before iteration has finished, this returns `None`.
+    pub fn into_remainder(self) -> Option<impl Iterator<Item = I::Item>> {
+        if self.builder.len() == 0 {
+            None
+        } else {
+            Some(self.builder.into_partial())
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if N == 0 {
+            return None;
+        }
+
+        loop {
+            self.builder.push(self.iter.next()?);
+            if let Some(chunk) = self.builder.take() {
+                return Some(chunk);
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// An iterator over the mapped overlapping windows of size `N` of another
+/// iterator.
+///
+/// See [`map_windows`] for more information.
+pub struct MapWindows<I, F, const N: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+    f: F,
+
+    /// The (possibly uninitialized) elements of the current window, always
+    /// stored in iteration order.
+    ///
+    /// # Safety
+    ///
+    /// The elements of `buffer[..filled]` are valid `I::Item`s.
+    buffer: [MaybeUninit<I::Item>; N],
+
+    /// The number of leading elements of `buffer` that are valid `I::Item`s.
+    /// Once this reaches `N` it stays there: from then on the buffer is
+    /// always full, and sliding the window keeps it full.
+    filled: usize,
+}
+
+/// Equivalent to `it.map_windows(f)`.
+pub fn map_windows<I, F, R, const N: usize>(iter: I, f: F) -> MapWindows<I, F, N>
+where
+    I: Iterator,
+    F: FnMut(&[I::Item; N]) -> R,
+{
+    MapWindows {
+        iter,
+        f,
+        buffer: [(); N].map(|_| MaybeUninit::uninit()),
+        filled: 0,
+    }
+}
+
+impl<I, F, R, const N: usize> Iterator for MapWindows<I, F, N>
+where
+    I: Iterator,
+    F: FnMut(&[I::Item; N]) -> R,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        if N == 0 {
+            return None;
+        }
+
+        if self.filled < N {
+            // Prime the buffer with the first N elements.
+            while self.filled < N {
+                self.buffer[self.filled] = MaybeUninit::new(self.iter.next()?);
+                self.filled += 1;
+            }
+        } else {
+            let item = self.iter.next()?;
+
+            // SAFETY: buffer[..N] is fully initialized, so buffer[0] is valid
+            // and must be dropped before we overwrite it below.
+            unsafe { self.buffer[0].assume_init_drop() };
+
+            // Slide the window down by one: shift buffer[1..N] into
+            // buffer[0..N - 1], then write the new item into the newly
+            // emptied last slot. This keeps the buffer in iteration order so
+            // it can be handed to `f` as a plain `&[T; N]` below.
+            //
+            // SAFETY: `ptr` is valid for reads and writes of `N` elements,
+            // and `ptr::copy` tolerates the source and destination ranges
+            // overlapping.
+            unsafe {
+                let ptr = self.buffer.as_mut_ptr();
+                ptr::copy(ptr.add(1), ptr, N - 1);
+            }
+            self.buffer[N - 1] = MaybeUninit::new(item);
+        }
+
+        // SAFETY: buffer[..N] is fully initialized and in iteration order.
+        let window = unsafe { &*(self.buffer.as_ptr().cast::<[I::Item; N]>()) };
+        Some((self.f)(window))
+    }
+}
+
+impl<I, F, const N: usize> Drop for MapWindows<I, F, N>
+where
+    I: Iterator,
+{
+    fn drop(&mut self) {
+        unsafe {
+            // SAFETY: buffer[..filled] is valid, so must be dropped. First we
+            // create a pointer to this valid slice, then drop that slice
+            // in-place, same as `ArrayBuilder`'s `Drop` impl.
+            let ptr_to_first: *mut MaybeUninit<I::Item> = self.buffer.as_mut_ptr();
+            let ptr_to_slice =
+                ptr::slice_from_raw_parts_mut(ptr_to_first.cast::<I::Item>(), self.filled);
+            ptr::drop_in_place(ptr_to_slice);
+        }
+    }
+}
@@ -2,7 +2,7 @@ use core::mem::{self, MaybeUninit};
 use core::ptr;
 
 /// An array of at most `N` elements.
-struct ArrayBuilder<T, const N: usize> {
+pub(crate) struct ArrayBuilder<T, const N: usize> {
     /// The (possibly uninitialized) elements of the `ArrayBuilder`.
     ///
     /// # Safety
@@ -16,7 +16,7 @@ struct ArrayBuilder<T, const N: usize> {
 
 impl<T, const N: usize> ArrayBuilder<T, N> {
     /// Initializes a new, empty `ArrayBuilder`.
-    pub fn new() -> Self {
+    pub(crate) fn new() -> Self {
         // SAFETY: the validity invariant trivially hold for a zero-length array.
         Self {
             arr: [(); N].map(|_| MaybeUninit::uninit()),
@@ -29,7 +29,7 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     /// # Panics
     ///
     /// This panics if `self.len() >= N`.
-    pub fn push(&mut self, value: T) {
+    pub(crate) fn push(&mut self, value: T) {
         // SAFETY: we maintain the invariant here that arr[..len] is valid.
         // Indexing with self.len also ensures self.len < N, and thus <= N after
         // the increment.
@@ -37,21 +37,44 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
         self.len += 1;
     }
 
+    /// Returns the number of elements currently stored in the builder.
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
     /// Consumes the elements in the `ArrayBuilder` and returns them as an array `[T; N]`.
     ///
     /// If `self.len() < N`, this returns `None`.
-    pub fn take(&mut self) -> Option<[T; N]> {
+    pub(crate) fn take(&mut self) -> Option<[T; N]> {
         if self.len == N {
-            // Take the array, resetting our length back to zero.
+            // Reset our length to zero. The bytes of `arr` are left as-is and
+            // will simply be overwritten by future `push` calls, so repeated
+            // `push`/`take` cycles never pay to reinitialize a fresh
+            // `MaybeUninit` buffer.
             self.len = 0;
-            let arr = mem::replace(&mut self.arr, [(); N].map(|_| MaybeUninit::uninit()));
 
-            // SAFETY: we had len == N, so all elements in arr are valid.
+            // SAFETY: we had len == N, so all elements of `self.arr` are
+            // valid, and `ptr::read` duplicates them without moving out of
+            // `self`. That duplication is sound here because we already reset
+            // `self.len` to zero above, so `self`'s `Drop` impl will no
+            // longer consider any of these elements live.
+            let arr = unsafe { ptr::read(&self.arr) };
             Some(unsafe { arr.map(|v| v.assume_init()) })
         } else {
             None
         }
     }
+
+    /// Takes the (possibly partial) elements out of the builder as a by-value
+    /// iterator, resetting the builder to empty without dropping them.
+    pub(crate) fn into_partial(mut self) -> PartialArray<T, N> {
+        let len = self.len;
+        // Reset our own length to zero so that our `Drop` impl has nothing left
+        // to do once the elements below have been handed off to `PartialArray`.
+        self.len = 0;
+        let arr = mem::replace(&mut self.arr, [(); N].map(|_| MaybeUninit::uninit()));
+        PartialArray { arr, start: 0, end: len }
+    }
 }
 
 impl<T, const N: usize> Drop for ArrayBuilder<T, N> {
@@ -68,7 +91,72 @@ impl<T, const N: usize> Drop for ArrayBuilder<T, N> {
     }
 }
 
+/// A by-value iterator over the elements of a partially filled `[T; N]`, as
+/// returned by [`next_array_or_partial`] when fewer than `N` elements were
+/// available.
+///
+/// Yields the elements in the order they were collected, and drops any
+/// not-yet-yielded elements when dropped.
+pub struct PartialArray<T, const N: usize> {
+    /// # Safety
+    ///
+    /// The elements of `arr[start..end]` are valid `T`s.
+    arr: [MaybeUninit<T>; N],
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> Iterator for PartialArray<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        // SAFETY: arr[start..end] is valid, and start < end here, so arr[start]
+        // is valid. We then advance start past it so it isn't dropped twice.
+        let value = unsafe { self.arr[self.start].as_ptr().read() };
+        self.start += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for PartialArray<T, N> {}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        unsafe {
+            // SAFETY: arr[start..end] is valid, so must be dropped. First we
+            // create a pointer to this valid slice, then drop that slice
+            // in-place, same as `ArrayBuilder`'s `Drop` impl.
+            let ptr_to_start: *mut MaybeUninit<T> = self.arr.as_mut_ptr().add(self.start);
+            let ptr_to_slice =
+                ptr::slice_from_raw_parts_mut(ptr_to_start.cast::<T>(), self.end - self.start);
+            ptr::drop_in_place(ptr_to_slice);
+        }
+    }
+}
+
 /// Equivalent to `it.next_array()`.
+///
+/// We previously tried an `ExactSizeIterator`-specialized fast path (skipping
+/// the per-element `Option` check once the remaining length is known) via a
+/// single `SpecNextArray` trait with impls on `I` and on `&mut I`. That
+/// compiled, but didn't work: calling `it.spec_next_array()` on `it: &mut I`
+/// resolves to the blanket `impl ... for I` before autoref ever reaches the
+/// `&mut I` impl, so the specialized path was silently dead code rather than
+/// a genuine speedup. A real autoref-specialization fast path needs two
+/// separate traits (one per impl), disambiguated by method name plus autoref,
+/// and even then `ExactSizeIterator::len()` is a safe, untrusted method, so it
+/// cannot justify `unwrap_unchecked` without risking UB from a buggy impl;
+/// real-`TrustedLen`-gated elision is nightly-only. We're declining to
+/// implement the specialization for now and keep the one checked loop below.
 pub fn next_array<I, T, const N: usize>(it: &mut I) -> Option<[T; N]>
 where
     I: Iterator<Item = T>,
@@ -79,3 +167,53 @@ where
     }
     builder.take()
 }
+
+/// Equivalent to `it.try_next_array()`.
+///
+/// Pulls at most `N` items from `it`. On the first `Err`, returns it
+/// immediately (dropping the `Ok` values collected so far). If `it` runs out
+/// before `N` items are collected, returns `None` (also dropping the partial
+/// successes). Otherwise returns the filled array.
+///
+/// `it` is taken by `&mut` and `next` is called at most `N` times, so `it`
+/// remains usable for its remaining items regardless of the outcome.
+pub fn try_next_array<I, T, E, const N: usize>(it: &mut I) -> Option<Result<[T; N], E>>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    let mut builder = ArrayBuilder::new();
+    for _ in 0..N {
+        match it.next()? {
+            Ok(value) => builder.push(value),
+            Err(e) => return Some(Err(e)),
+        }
+    }
+
+    // The loop above pushed exactly N values without early-returning, so the
+    // builder is guaranteed to be full here.
+    Some(Ok(builder.take().expect("builder should be full")))
+}
+
+/// Equivalent to `it.next_array_or_partial()`.
+///
+/// Like `next_array`, but instead of dropping the collected elements when
+/// `it` runs out before `N` items are reached, returns them as a by-value
+/// [`PartialArray`] iterator so no data is lost.
+pub fn next_array_or_partial<I, T, const N: usize>(
+    it: &mut I,
+) -> Result<[T; N], PartialArray<T, N>>
+where
+    I: Iterator<Item = T>,
+{
+    let mut builder = ArrayBuilder::new();
+    for _ in 0..N {
+        match it.next() {
+            Some(value) => builder.push(value),
+            None => return Err(builder.into_partial()),
+        }
+    }
+
+    // The loop above pushed exactly N values without early-returning, so the
+    // builder is guaranteed to be full here.
+    Ok(builder.take().expect("builder should be full"))
+}